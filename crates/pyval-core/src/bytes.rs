@@ -0,0 +1,54 @@
+//! Byte-slice validation - run the full validator on raw, possibly
+//! non-UTF-8 bytes pulled straight off the wire (IMAP/SMTP), without an
+//! intermediate allocation or a lossy decode.
+
+use crate::domain::validate_domain;
+use crate::error::EmailError;
+use crate::syntax::validate_local_part;
+
+/// Validate an address given as raw bytes. ASCII structure (single `@`)
+/// is checked directly on the bytes; a segment containing a high bit is
+/// only decoded as UTF-8 - and only if `allow_smtputf8` is set - before
+/// the existing Unicode-safety rules run on it.
+pub fn validate_bytes(bytes: &[u8], allow_smtputf8: bool) -> Result<(), EmailError> {
+    if bytes.is_empty() {
+        return Err(EmailError::Empty);
+    }
+
+    let mut at_pos = None;
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == b'@' {
+            if at_pos.is_some() {
+                return Err(EmailError::MultipleAt);
+            }
+            at_pos = Some(i);
+        }
+    }
+    let at_pos = at_pos.ok_or(EmailError::MissingAt)?;
+
+    let local = decode_segment(&bytes[..at_pos], allow_smtputf8)?;
+    let domain = decode_segment(&bytes[at_pos + 1..], allow_smtputf8)?;
+
+    // Raw-bytes validation mirrors the strict validator's default rules, not
+    // the mailbox parser's - quoted local parts are always rejected here.
+    validate_local_part(&local, allow_smtputf8, false)?;
+    // Raw-bytes validation mirrors the strict validator's default rules, not
+    // the mailbox parser's - domain literals are always rejected here.
+    validate_domain(&domain, false)?;
+
+    Ok(())
+}
+
+/// Borrow a byte segment as `&str` without copying. A segment with any
+/// high bit requires `allow_smtputf8` and must be well-formed UTF-8.
+fn decode_segment(bytes: &[u8], allow_smtputf8: bool) -> Result<&str, EmailError> {
+    if bytes.iter().all(|&b| b < 128) {
+        return std::str::from_utf8(bytes).map_err(|_| EmailError::InvalidUtf8);
+    }
+
+    if !allow_smtputf8 {
+        return Err(EmailError::InvalidCharacter);
+    }
+
+    std::str::from_utf8(bytes).map_err(|_| EmailError::InvalidUtf8)
+}
@@ -0,0 +1,66 @@
+//! Anti-signup-fraud classification: disposable-domain and role-account
+//! detection, layered on top of a validated address rather than baked
+//! into the syntax checks themselves.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+static ROLE_ACCOUNTS: OnceLock<HashSet<&'static str>> = OnceLock::new();
+static DISPOSABLE_DOMAINS: OnceLock<HashSet<&'static str>> = OnceLock::new();
+
+fn get_role_accounts() -> &'static HashSet<&'static str> {
+    ROLE_ACCOUNTS.get_or_init(|| {
+        [
+            "admin",
+            "info",
+            "support",
+            "noreply",
+            "no-reply",
+            "postmaster",
+            "sales",
+            "abuse",
+            "billing",
+            "contact",
+            "webmaster",
+            "hostmaster",
+        ]
+        .iter()
+        .cloned()
+        .collect()
+    })
+}
+
+fn get_disposable_domains() -> &'static HashSet<&'static str> {
+    DISPOSABLE_DOMAINS.get_or_init(|| {
+        [
+            "mailinator.com",
+            "guerrillamail.com",
+            "10minutemail.com",
+            "tempmail.com",
+            "throwaway.email",
+            "yopmail.com",
+            "trashmail.com",
+            "getnada.com",
+            "dispostable.com",
+            "fakeinbox.com",
+        ]
+        .iter()
+        .cloned()
+        .collect()
+    })
+}
+
+/// Whether `local_part` names a shared/role mailbox rather than a person,
+/// after stripping any `+tag` subaddress and casefolding.
+pub fn is_role_account(local_part: &str) -> bool {
+    let base = match local_part.find('+') {
+        Some(pos) => &local_part[..pos],
+        None => local_part,
+    };
+    get_role_accounts().contains(base.to_lowercase().as_str())
+}
+
+/// Whether `ascii_domain` belongs to a known throwaway/disposable provider.
+pub fn is_disposable_domain(ascii_domain: &str) -> bool {
+    get_disposable_domains().contains(ascii_domain.to_lowercase().as_str())
+}
@@ -0,0 +1,73 @@
+//! DNS-backed deliverability checks (MX, falling back to A/AAAA per the
+//! RFC 5321 implicit-MX rule). Gated behind the `deliverability` feature
+//! so the pure-syntax build stays free of an async runtime and resolver.
+
+use std::sync::OnceLock;
+
+use crate::error::EmailError;
+use hickory_resolver::TokioAsyncResolver;
+
+static RESOLVER: OnceLock<TokioAsyncResolver> = OnceLock::new();
+
+/// The process-wide resolver, built from `/etc/resolv.conf` once and
+/// reused by every lookup - `resolve_many` fans out dozens of concurrent
+/// lookups and shouldn't reconfigure (and re-read) a resolver per domain.
+fn shared_resolver() -> Result<&'static TokioAsyncResolver, EmailError> {
+    if let Some(resolver) = RESOLVER.get() {
+        return Ok(resolver);
+    }
+    let resolver =
+        TokioAsyncResolver::tokio_from_system_conf().map_err(|_| EmailError::DomainNotFound)?;
+    Ok(RESOLVER.get_or_init(|| resolver))
+}
+
+/// Result of resolving a domain's mail-acceptance records.
+#[derive(Debug, Clone, Default)]
+pub struct MxLookupResult {
+    /// MX target hostnames, in priority order. Empty if the domain falls
+    /// back to the A/AAAA implicit-MX rule.
+    pub mx_records: Vec<String>,
+    /// Whether the domain resolved to *something* that can receive mail -
+    /// an MX record, or an A/AAAA record when no MX exists.
+    pub accepts_mail: bool,
+}
+
+/// Resolve MX records for `ascii_domain`, falling back to A/AAAA lookups
+/// (RFC 5321 section 5.1 implicit MX) when the domain has none.
+pub async fn resolve_domain(ascii_domain: &str) -> Result<MxLookupResult, EmailError> {
+    let resolver = shared_resolver()?;
+
+    if let Ok(mx) = resolver.mx_lookup(ascii_domain).await {
+        let mut records: Vec<_> = mx.iter().collect();
+        records.sort_by_key(|record| record.preference());
+        let mx_records: Vec<String> = records
+            .into_iter()
+            .map(|record| record.exchange().to_string())
+            .collect();
+        if !mx_records.is_empty() {
+            return Ok(MxLookupResult {
+                accepts_mail: true,
+                mx_records,
+            });
+        }
+    }
+    // No MX (or an `Ok` response with zero records) - fall through to the
+    // implicit-MX A/AAAA check below.
+
+    let has_a = resolver.lookup_ip(ascii_domain).await.is_ok();
+    if has_a {
+        Ok(MxLookupResult {
+            mx_records: Vec::new(),
+            accepts_mail: true,
+        })
+    } else {
+        Err(EmailError::NoMxRecord)
+    }
+}
+
+/// Resolve many domains concurrently, preserving input order, so a batch
+/// of addresses doesn't pay one DNS round-trip per call in sequence.
+pub async fn resolve_many(domains: &[String]) -> Vec<Result<MxLookupResult, EmailError>> {
+    let futures = domains.iter().map(|domain| resolve_domain(domain));
+    futures::future::join_all(futures).await
+}
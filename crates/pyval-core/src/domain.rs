@@ -4,21 +4,32 @@ use crate::error::EmailError;
 use idna::domain_to_ascii;
 
 #[inline]
-pub fn validate_domain(domain: &str) -> Result<String, EmailError> {
+pub fn validate_domain(domain: &str, allow_domain_literal: bool) -> Result<String, EmailError> {
     if domain.is_empty() {
         return Err(EmailError::InvalidDomain);
     }
-    
+
     // Handle IP literals [192.168.1.1] or [IPv6:...]
     if domain.starts_with('[') && domain.ends_with(']') {
+        if !allow_domain_literal {
+            return Err(EmailError::InvalidAddressLiteral);
+        }
         return validate_ip_literal(domain);
     }
-    
+
     // Check length
     if domain.len() > 253 {
         return Err(EmailError::DomainTooLong);
     }
-    
+
+    // Homograph guard: check each non-ASCII label for single-script
+    // consistency before IDNA folds it into an opaque `xn--` form.
+    for label in domain.split('.') {
+        if !label.is_ascii() {
+            crate::syntax::check_single_script(label)?;
+        }
+    }
+
     // Convert to ASCII (handles IDN)
     let ascii_domain = domain_to_ascii(domain)
         .map_err(|_| EmailError::InvalidDomain)?;
@@ -70,16 +81,42 @@ fn validate_domain_label(label: &str) -> Result<(), EmailError> {
 #[inline]
 fn validate_ip_literal(domain: &str) -> Result<String, EmailError> {
     let inner = &domain[1..domain.len()-1];
-    
+
+    if inner.is_empty() {
+        return Err(EmailError::InvalidAddressLiteral);
+    }
+
     if let Some(ipv6) = inner.strip_prefix("IPv6:") {
-        // Validate IPv6
+        // Validate IPv6, including "::" compression and an embedded IPv4 tail.
         ipv6.parse::<std::net::Ipv6Addr>()
-            .map_err(|_| EmailError::InvalidDomain)?;
-    } else {
-        // Validate IPv4
-        inner.parse::<std::net::Ipv4Addr>()
-            .map_err(|_| EmailError::InvalidDomain)?;
+            .map_err(|_| EmailError::InvalidAddressLiteral)?;
+        return Ok(domain.to_string());
     }
-    
-    Ok(domain.to_string())
+
+    if inner.parse::<std::net::Ipv4Addr>().is_ok() {
+        return Ok(domain.to_string());
+    }
+
+    // General-address-literal: `[tag:value]` where tag is a standardized-tag
+    // (dot-separated Ldh segments) and value is one or more dtext bytes.
+    match inner.find(':') {
+        Some(colon) if is_standardized_tag(&inner[..colon]) && is_dcontent(&inner[colon + 1..]) => {
+            Ok(domain.to_string())
+        }
+        _ => Err(EmailError::InvalidAddressLiteral),
+    }
+}
+
+fn is_standardized_tag(tag: &str) -> bool {
+    !tag.is_empty()
+        && tag.split('.').all(|seg| {
+            !seg.is_empty()
+                && !seg.starts_with('-')
+                && !seg.ends_with('-')
+                && seg.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        })
+}
+
+fn is_dcontent(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| matches!(b, 33..=90 | 94..=126))
 }
@@ -31,9 +31,30 @@ pub enum EmailError {
     #[error("The domain is not valid")]
     InvalidDomain,
 
+    #[error("The address literal is not valid")]
+    InvalidAddressLiteral,
+
+    #[error("The quoted local part is missing its closing quote")]
+    QuotedStringUnterminated,
+
+    #[error("The quoted local part contains an invalid character")]
+    InvalidQuotedChar,
+
+    #[error("The address mixes characters from incompatible scripts")]
+    MixedScript,
+
+    #[error("The address is not valid UTF-8")]
+    InvalidUtf8,
+
     #[error("Invalid character in the email address")]
     InvalidCharacter,
 
+    #[error("The domain does not exist")]
+    DomainNotFound,
+
+    #[error("The domain has no MX record and does not accept mail directly")]
+    NoMxRecord,
+
     #[error("The email address is not valid")]
     Generic,
 }
@@ -87,6 +87,12 @@ pub fn fast_ascii_email_check(email: &str) -> Option<bool> {
         return Some(false);
     }
 
+    // A quoted local part (`"john doe"@example.com`) needs the full
+    // qcontent parser.
+    if local.as_bytes()[0] == b'"' {
+        return None;
+    }
+
     // Must have dot in domain
     if !domain.contains('.') {
         return Some(false);
@@ -165,6 +171,12 @@ pub fn ultra_fast_ascii_check(email: &str) -> Option<bool> {
         return Some(false);
     }
 
+    // A quoted local part (`"john doe"@example.com`) needs the full
+    // qcontent parser.
+    if bytes[0] == b'"' {
+        return None;
+    }
+
     // Quick check for @
     let mut at_found = false;
     let mut at_pos = 0;
@@ -185,8 +197,14 @@ pub fn ultra_fast_ascii_check(email: &str) -> Option<bool> {
         return Some(false);
     }
 
-    // Check domain has dot
+    // Address literals (`[192.168.0.1]`, `[IPv6:...]`) need the full
+    // domain parser to enforce `allow_domain_literal` and the bracket grammar.
     let domain = &bytes[at_pos + 1..];
+    if domain.first() == Some(&b'[') {
+        return None;
+    }
+
+    // Check domain has dot
     if !domain.contains(&b'.') {
         return Some(false);
     }
@@ -15,6 +15,19 @@ impl ZeroCopyValidator {
             return false;
         }
 
+        // A quoted-string local part (`"john doe"@example.com`) isn't a
+        // dot-atom, so this state machine can't validate it - bail to the
+        // full syntax/domain checks rather than rejecting it outright.
+        if bytes[0] == b'"' {
+            return match bytes.iter().rposition(|&b| b == b'@') {
+                Some(at_pos) => {
+                    crate::syntax::validate_local_part(&email[..at_pos], true, true).is_ok()
+                        && crate::domain::validate_domain(&email[at_pos + 1..], true).is_ok()
+                }
+                None => false,
+            };
+        }
+
         let mut state = ParseState::LocalStart;
         let mut at_count = 0;
         let mut dot_count = 0;
@@ -3,11 +3,16 @@
 //! This crate provides the core email validation logic
 //! that can be used by any language wrapper.
 
+pub mod bytes;
+pub mod classify;
+#[cfg(feature = "deliverability")]
+pub mod deliverability;
 pub mod domain;
 pub mod error;
 pub mod fastpath;
 pub mod lazy;
 pub mod lookup;
+pub mod mailbox;
 pub mod simd;
 pub mod syntax;
 pub mod validator;
@@ -0,0 +1,167 @@
+//! Mailbox parsing: `display-name <addr-spec>` and comma-separated address
+//! lists pulled straight out of `From`/`To`/`Cc` header values.
+
+use crate::domain::validate_domain;
+use crate::error::EmailError;
+use crate::syntax::validate_local_part;
+
+/// A parsed mailbox: an optional display name plus the validated address.
+#[derive(Debug, Clone)]
+pub struct Mailbox {
+    pub display_name: Option<String>,
+    pub local_part: String,
+    pub domain: String,
+}
+
+impl Mailbox {
+    /// Reconstruct a normalized `local@domain` address.
+    pub fn normalized(&self) -> String {
+        format!("{}@{}", self.local_part, self.domain)
+    }
+}
+
+/// Parse a single mailbox, with or without a display name / angle-addr.
+pub fn parse_mailbox(input: &str, allow_smtputf8: bool) -> Result<Mailbox, EmailError> {
+    let cleaned = strip_comments(&unfold(input));
+    let s = cleaned.trim();
+
+    let (display_name, addr_spec) = if let Some(open) = s.find('<') {
+        let close = s.rfind('>').ok_or(EmailError::Generic)?;
+        if close < open {
+            return Err(EmailError::Generic);
+        }
+        let name = if open == 0 {
+            None
+        } else {
+            decode_display_name(s[..open].trim())
+        };
+        (name, s[open + 1..close].trim())
+    } else {
+        (None, s)
+    };
+
+    let at_pos = addr_spec.find('@').ok_or(EmailError::MissingAt)?;
+    let local_part = &addr_spec[..at_pos];
+    let domain = &addr_spec[at_pos + 1..];
+
+    // Mailbox parsing targets header values, where quoted local parts are
+    // legal RFC 5322 syntax - always allowed here, unlike the strict
+    // validator where it's opt-in.
+    validate_local_part(local_part, allow_smtputf8, true)?;
+    // Mailbox parsing targets header values, where address literals are
+    // legal RFC 5321/5322 syntax - always allowed here, unlike the strict
+    // validator where it's opt-in.
+    let ascii_domain = validate_domain(domain, true)?;
+
+    Ok(Mailbox {
+        display_name,
+        local_part: local_part.to_string(),
+        domain: ascii_domain,
+    })
+}
+
+/// Parse a full header value containing a comma-separated list of mailboxes.
+pub fn parse_address_list(input: &str, allow_smtputf8: bool) -> Vec<Result<Mailbox, EmailError>> {
+    let cleaned = strip_comments(&unfold(input));
+    split_top_level(&cleaned)
+        .into_iter()
+        .map(|part| parse_mailbox(part.trim(), allow_smtputf8))
+        .collect()
+}
+
+fn decode_display_name(s: &str) -> Option<String> {
+    if s.is_empty() {
+        return None;
+    }
+    if s.as_bytes()[0] == b'"' && s.as_bytes().last() == Some(&b'"') && s.len() >= 2 {
+        Some(unescape(&s[1..s.len() - 1]))
+    } else {
+        Some(s.to_string())
+    }
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(next) = chars.next() {
+                out.push(next);
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Unfold CRLF+WSP folding whitespace into nothing extra (headers arrive
+/// as a single logical line once folding is undone).
+fn unfold(s: &str) -> String {
+    s.replace("\r\n", "").replace(['\r', '\n'], "")
+}
+
+/// Strip `(...)` CFWS comments, respecting a quoted-string so a `(` inside
+/// a display name or quoted local part isn't mistaken for a comment.
+fn strip_comments(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = String::with_capacity(s.len());
+    let mut depth = 0u32;
+    let mut in_quotes = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if in_quotes {
+            out.push(b as char);
+            if b == b'\\' && i + 1 < bytes.len() {
+                i += 1;
+                out.push(bytes[i] as char);
+            } else if b == b'"' {
+                in_quotes = false;
+            }
+            i += 1;
+            continue;
+        }
+        match b {
+            b'"' => {
+                in_quotes = true;
+                out.push('"');
+            }
+            b'(' => depth += 1,
+            b')' if depth > 0 => depth -= 1,
+            b'\\' if depth > 0 && i + 1 < bytes.len() => i += 1,
+            _ if depth == 0 => out.push(b as char),
+            _ => {}
+        }
+        i += 1;
+    }
+    out
+}
+
+/// Split on commas that aren't inside a quoted-string or `<...>`.
+fn split_top_level(s: &str) -> Vec<&str> {
+    let bytes = s.as_bytes();
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut angle_depth = 0i32;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => in_quotes = !in_quotes,
+            b'\\' if in_quotes => i += 1,
+            b'<' if !in_quotes => angle_depth += 1,
+            b'>' if !in_quotes => angle_depth -= 1,
+            b',' if !in_quotes && angle_depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    if start < s.len() {
+        parts.push(&s[start..]);
+    }
+    parts
+}
@@ -150,8 +150,14 @@ impl PortableSimd {
             return Some(false);
         }
 
-        // Check for dot in domain
+        // Address literals (`[192.168.0.1]`, `[IPv6:...]`) need the full
+        // domain parser to enforce `allow_domain_literal` and the bracket grammar.
         let domain = &email[at_pos + 1..];
+        if domain.starts_with('[') {
+            return None;
+        }
+
+        // Check for dot in domain
         if !domain.contains('.') {
             return Some(false);
         }
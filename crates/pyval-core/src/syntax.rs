@@ -1,21 +1,34 @@
 //! RFC 5322 / RFC 6531 email syntax validation
 
 use crate::error::EmailError;
+use unicode_script::{Script, UnicodeScript};
 
-/// Validates the local part (before @) of an email address
+/// Validates the local part (before @) of an email address. Returns
+/// whether the local part was a quoted-string, so callers can round-trip
+/// that fact onto `ValidatedEmail`.
 #[inline]
-pub fn validate_local_part(local: &str, allow_smtputf8: bool) -> Result<(), EmailError> {
+pub fn validate_local_part(
+    local: &str,
+    allow_smtputf8: bool,
+    allow_quoted_local: bool,
+) -> Result<bool, EmailError> {
     if local.is_empty() {
         return Err(EmailError::Empty);
     }
-    
+
     if local.len() > 64 {
         return Err(EmailError::LocalPartTooLong);
     }
-    
-    // Fast byte-level checks first
+
     let bytes = local.as_bytes();
-    
+
+    if bytes[0] == b'"' {
+        if !allow_quoted_local {
+            return Err(EmailError::InvalidCharacter);
+        }
+        return validate_quoted_local_part(local).map(|_| true);
+    }
+
     if bytes[0] == b'.' {
         return Err(EmailError::LeadingDot);
     }
@@ -53,9 +66,52 @@ pub fn validate_local_part(local: &str, allow_smtputf8: bool) -> Result<(), Emai
                 return Err(EmailError::InvalidCharacter);
             }
         }
+
+        // Guard against homograph spoofing: a single label mixing scripts
+        // (e.g. Latin `a` + Cyrillic `а`) is rejected unless it's one of
+        // the standard Japanese/Korean multi-script combinations.
+        check_single_script(local)?;
     }
-    
-    Ok(())
+
+    Ok(false)
+}
+
+/// UTS-39-style single-script check: after dropping `Common` and
+/// `Inherited` characters, the remaining scripts must reduce to one -
+/// with the standard allowance for Japanese (Han+Hiragana+Katakana+Latin)
+/// and Korean (Hangul+Han+Latin) mixed text.
+pub(crate) fn check_single_script(label: &str) -> Result<(), EmailError> {
+    let mut scripts: Vec<Script> = Vec::new();
+    for c in label.chars() {
+        let script = c.script();
+        if matches!(script, Script::Common | Script::Inherited) {
+            continue;
+        }
+        if !scripts.contains(&script) {
+            scripts.push(script);
+        }
+    }
+
+    if is_single_script(&scripts) {
+        Ok(())
+    } else {
+        Err(EmailError::MixedScript)
+    }
+}
+
+fn is_single_script(scripts: &[Script]) -> bool {
+    if scripts.len() <= 1 {
+        return true;
+    }
+
+    let is_japanese = scripts
+        .iter()
+        .all(|s| matches!(s, Script::Han | Script::Hiragana | Script::Katakana | Script::Latin));
+    let is_korean = scripts
+        .iter()
+        .all(|s| matches!(s, Script::Hangul | Script::Han | Script::Latin));
+
+    is_japanese || is_korean
 }
 
 /// Characters allowed in local part (unquoted) - byte version
@@ -72,6 +128,57 @@ fn is_valid_local_byte(b: u8, allow_smtputf8: bool) -> bool {
     }
 }
 
+/// Validate a quoted-string local part: `"qcontent*"` where `qcontent` is
+/// printable ASCII (ordinary spaces included) and `\` escapes the next byte,
+/// so `\"` and `\\` are the only way to get a literal quote or backslash.
+#[inline]
+fn validate_quoted_local_part(local: &str) -> Result<(), EmailError> {
+    let bytes = local.as_bytes();
+
+    if bytes.len() < 2 || bytes[bytes.len() - 1] != b'"' {
+        return Err(EmailError::QuotedStringUnterminated);
+    }
+
+    let interior = &bytes[1..bytes.len() - 1];
+    let mut i = 0;
+    let mut closed_early = false;
+
+    while i < interior.len() {
+        let b = interior[i];
+        if b == b'\\' {
+            if i + 1 >= interior.len() {
+                return Err(EmailError::QuotedStringUnterminated);
+            }
+            i += 2;
+            continue;
+        }
+        if b == b'"' {
+            // A bare quote before the final byte means the quoted string
+            // ended early and trailing content follows - not a valid
+            // dot-atom-only local part.
+            closed_early = true;
+            break;
+        }
+        if !is_qcontent_byte(b) {
+            return Err(EmailError::InvalidQuotedChar);
+        }
+        i += 1;
+    }
+
+    if closed_early {
+        return Err(EmailError::InvalidQuotedChar);
+    }
+
+    Ok(())
+}
+
+/// `qcontent` - printable ASCII and ordinary space/tab; control characters
+/// (other than escaped ones) are rejected.
+#[inline(always)]
+fn is_qcontent_byte(b: u8) -> bool {
+    b == b' ' || b == b'\t' || (0x21..=0x7e).contains(&b) && b != b'"' && b != b'\\'
+}
+
 /// Check for unsafe unicode characters
 #[inline]
 fn is_unsafe_unicode(c: char) -> bool {
@@ -13,17 +13,97 @@ pub struct ValidatedEmail {
     pub normalized: String,
     pub ascii_domain: String,
     pub smtputf8: bool,
+    /// Deduplicated "same inbox" form - not just NFC-normalized like
+    /// `normalized`, but with the subaddress tag stripped and, for
+    /// providers known to be dot-insensitive, local-part dots removed too.
+    pub canonical: String,
+    /// Whether the local part was a quoted-string (`"john doe"@example.com`)
+    /// rather than a plain dot-atom, so callers can round-trip it.
+    pub is_quoted_local: bool,
+    /// Whether the domain was a bracketed address literal (`[192.168.0.1]`,
+    /// `[IPv6:...]`) rather than a name, so callers can round-trip it.
+    pub is_domain_literal: bool,
+    /// Whether the domain matched a known disposable/throwaway provider.
+    /// Only populated when `EmailValidator::check_disposable` is set.
+    pub is_disposable: bool,
+    /// Whether the local part is a shared/role mailbox (`admin`, `support`,
+    /// ...). Only populated when `EmailValidator::check_role_account` is set.
+    pub is_role_account: bool,
+    /// MX target hostnames, resolved by `EmailValidator::validate_async` when
+    /// `check_deliverability` is set (requires the `deliverability` feature).
+    /// `EmailValidator::validate` does no DNS lookup and leaves this empty.
+    pub mx_records: Vec<String>,
+    /// Whether the domain resolved to something that can receive mail. Only
+    /// populated by `EmailValidator::validate_async` when `check_deliverability`
+    /// is set; `EmailValidator::validate` leaves it `false`.
+    pub accepts_mail: bool,
+}
+
+impl ValidatedEmail {
+    /// Recompute the canonical form from the current `local_part`/`ascii_domain`.
+    /// Exposed so callers can re-derive it without re-running full validation.
+    pub fn canonicalize(&self) -> String {
+        canonicalize(&self.local_part, &self.ascii_domain)
+    }
+}
+
+/// Per-domain canonicalization behavior: which byte separates the
+/// subaddress tag, whether local-part dots are insignificant, and which
+/// canonical domain an alias domain folds into.
+struct CanonicalRule {
+    separator: char,
+    strip_dots: bool,
+    fold_domain: Option<&'static str>,
+}
+
+const DEFAULT_RULE: CanonicalRule = CanonicalRule {
+    separator: '+',
+    strip_dots: false,
+    fold_domain: None,
+};
+
+fn canonical_rule_for(domain: &str) -> CanonicalRule {
+    match domain {
+        "gmail.com" | "googlemail.com" => CanonicalRule {
+            separator: '+',
+            strip_dots: true,
+            fold_domain: Some("gmail.com"),
+        },
+        _ => DEFAULT_RULE,
+    }
+}
+
+/// Collapse `local@domain` to a single deduplicated key: strip the
+/// subaddress tag (and, for dot-insensitive providers, the dots), fold
+/// alias domains, and lowercase throughout. Idempotent - canonicalizing
+/// an already-canonical address is a no-op.
+fn canonicalize(local_part: &str, ascii_domain: &str) -> String {
+    let rule = canonical_rule_for(ascii_domain);
+
+    let base_local = match local_part.find(rule.separator) {
+        Some(pos) => &local_part[..pos],
+        None => local_part,
+    };
+
+    let mut lower_local = base_local.to_lowercase();
+    if rule.strip_dots {
+        lower_local = lower_local.replace('.', "");
+    }
+
+    let domain = rule.fold_domain.unwrap_or(ascii_domain).to_lowercase();
+
+    format!("{}@{}", lower_local, domain)
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct EmailValidator {
     pub allow_smtputf8: bool,
-    #[allow(dead_code)]
     pub allow_quoted_local: bool,
-    #[allow(dead_code)]
     pub allow_domain_literal: bool,
     #[allow(dead_code)]
     pub check_deliverability: bool,
+    pub check_disposable: bool,
+    pub check_role_account: bool,
 }
 
 #[allow(dead_code)]
@@ -34,6 +114,8 @@ impl EmailValidator {
             allow_quoted_local: false,
             allow_domain_literal: false,
             check_deliverability: false,
+            check_disposable: false,
+            check_role_account: false,
         }
     }
 
@@ -92,8 +174,10 @@ impl EmailValidator {
         let domain = &email[at_pos + 1..];
 
         // Validate parts
-        validate_local_part(local_part, self.allow_smtputf8)?;
-        let ascii_domain = validate_domain(domain)?;
+        let is_quoted_local =
+            validate_local_part(local_part, self.allow_smtputf8, self.allow_quoted_local)?;
+        let is_domain_literal = domain.starts_with('[');
+        let ascii_domain = validate_domain(domain, self.allow_domain_literal)?;
 
         // Normalize - only NFC if needed
         let normalized_local: String = if local_part.is_ascii() {
@@ -111,6 +195,11 @@ impl EmailValidator {
         // Check if SMTPUTF8 is required
         let smtputf8 = !local_part.is_ascii();
 
+        let canonical = canonicalize(&normalized_local, &ascii_domain);
+
+        let is_disposable = self.check_disposable && crate::classify::is_disposable_domain(&ascii_domain);
+        let is_role_account = self.check_role_account && crate::classify::is_role_account(local_part);
+
         Ok(ValidatedEmail {
             original: email.to_string(),
             local_part: local_part.to_string(),
@@ -118,6 +207,41 @@ impl EmailValidator {
             normalized,
             ascii_domain,
             smtputf8,
+            canonical,
+            is_quoted_local,
+            is_domain_literal,
+            is_disposable,
+            is_role_account,
+            mx_records: Vec::new(),
+            accepts_mail: false,
         })
     }
+
+    /// Like `validate`, but when `check_deliverability` is set, also
+    /// resolves the domain's MX (falling back to A/AAAA) records before
+    /// returning. Requires the `deliverability` feature.
+    #[cfg(feature = "deliverability")]
+    pub async fn validate_async(&self, email: &str) -> Result<ValidatedEmail, EmailError> {
+        let mut validated = self.validate(email)?;
+
+        if self.check_deliverability {
+            let lookup = crate::deliverability::resolve_domain(&validated.ascii_domain).await?;
+            validated.mx_records = lookup.mx_records;
+            validated.accepts_mail = lookup.accepts_mail;
+        }
+
+        Ok(validated)
+    }
+}
+
+/// Validate many emails concurrently, resolving deliverability for each
+/// in parallel rather than blocking one DNS round-trip at a time. Requires
+/// the `deliverability` feature.
+#[cfg(feature = "deliverability")]
+pub async fn validate_batch_async(
+    validator: &EmailValidator,
+    emails: &[String],
+) -> Vec<Result<ValidatedEmail, EmailError>> {
+    let futures = emails.iter().map(|email| validator.validate_async(email));
+    futures::future::join_all(futures).await
 }
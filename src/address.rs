@@ -0,0 +1,321 @@
+//! RFC 5322 address parsing for real header values - display names,
+//! angle-addr, quoted locals, domain literals, and groups.
+
+use crate::lookup::{is_valid_domain_byte_fast, is_valid_local_byte_fast};
+
+/// A single parsed mailbox: an optional display name plus its addr-spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedAddress {
+    pub display_name: Option<String>,
+    pub local_part: String,
+    pub domain: String,
+}
+
+impl ParsedAddress {
+    pub fn addr_spec(&self) -> String {
+        format!("{}@{}", self.local_part, self.domain)
+    }
+}
+
+/// A `group-name: member, member;` construct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressGroup {
+    pub display_name: String,
+    pub members: Vec<ParsedAddress>,
+}
+
+/// Either a bare/display-named mailbox or a group of mailboxes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsedEntry {
+    Address(ParsedAddress),
+    Group(AddressGroup),
+}
+
+/// Parses RFC 5322 `address` values - mailboxes and groups - out of raw
+/// header text, reusing the fast-path character tables for the atext
+/// portions and adding quoted-string/domain-literal modes on top.
+pub struct AddressParser;
+
+impl AddressParser {
+    /// Parse a single `address` (mailbox or group).
+    pub fn parse(input: &str) -> Option<ParsedEntry> {
+        let cleaned = strip_comments(&unfold(input));
+        let s = cleaned.trim();
+
+        if let Some(colon) = find_group_colon(s) {
+            let group_name = decode_display_name(s[..colon].trim())?;
+            let rest = s[colon + 1..].trim();
+            let rest = rest.strip_suffix(';').unwrap_or(rest);
+            let members = split_addresses(rest)
+                .into_iter()
+                .filter_map(|m| Self::parse_mailbox(m.trim()))
+                .collect();
+            return Some(ParsedEntry::Group(AddressGroup {
+                display_name: group_name,
+                members,
+            }));
+        }
+
+        Self::parse_mailbox(s).map(ParsedEntry::Address)
+    }
+
+    /// Parse a full header value containing a comma-separated address list.
+    pub fn parse_list(input: &str) -> Vec<ParsedEntry> {
+        let cleaned = strip_comments(&unfold(input));
+        split_top_level_entries(&cleaned)
+            .into_iter()
+            .filter_map(|entry| Self::parse(entry.trim()))
+            .collect()
+    }
+
+    fn parse_mailbox(s: &str) -> Option<ParsedAddress> {
+        let s = s.trim();
+
+        if let Some(open) = s.find('<') {
+            let close = s.rfind('>')?;
+            if close < open {
+                return None;
+            }
+            let display_name = if open == 0 {
+                None
+            } else {
+                decode_display_name(s[..open].trim())
+            };
+            let addr_spec = s[open + 1..close].trim();
+            let (local_part, domain) = parse_addr_spec(addr_spec)?;
+            return Some(ParsedAddress {
+                display_name,
+                local_part,
+                domain,
+            });
+        }
+
+        // `alex@adnab.me (comment)` - comments were already stripped above,
+        // so a bare addr-spec with no angle brackets is what remains.
+        let (local_part, domain) = parse_addr_spec(s)?;
+        Some(ParsedAddress {
+            display_name: None,
+            local_part,
+            domain,
+        })
+    }
+}
+
+/// Split `local@domain` respecting a quoted local part and a bracketed
+/// domain literal, both of which may contain `@`.
+fn parse_addr_spec(s: &str) -> Option<(String, String)> {
+    let bytes = s.as_bytes();
+    let at_pos = if bytes.first() == Some(&b'"') {
+        let end = find_quote_end(bytes)?;
+        bytes[end + 1..].iter().position(|&b| b == b'@').map(|p| end + 1 + p)?
+    } else {
+        s.find('@')?
+    };
+
+    let local = &s[..at_pos];
+    let domain = &s[at_pos + 1..];
+
+    if !validate_local(local) || !validate_domain(domain) {
+        return None;
+    }
+
+    Some((local.to_string(), domain.to_string()))
+}
+
+fn find_quote_end(bytes: &[u8]) -> Option<usize> {
+    let mut i = 1;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' {
+            i += 2;
+            continue;
+        }
+        if bytes[i] == b'"' {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+fn validate_local(local: &str) -> bool {
+    if local.is_empty() {
+        return false;
+    }
+    let bytes = local.as_bytes();
+    if bytes[0] == b'"' {
+        return find_quote_end(bytes) == Some(bytes.len() - 1);
+    }
+    if bytes[0] == b'.' || bytes[bytes.len() - 1] == b'.' {
+        return false;
+    }
+    let mut prev_dot = false;
+    for &b in bytes {
+        if b == b'.' {
+            if prev_dot {
+                return false;
+            }
+            prev_dot = true;
+        } else {
+            prev_dot = false;
+            if !is_valid_local_byte_fast(b) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn validate_domain(domain: &str) -> bool {
+    if domain.is_empty() {
+        return false;
+    }
+    let bytes = domain.as_bytes();
+    if bytes[0] == b'[' {
+        return crate::literal::is_valid_domain_literal(domain);
+    }
+    if bytes[0] == b'.' || bytes[bytes.len() - 1] == b'.' {
+        return false;
+    }
+    for label in domain.split('.') {
+        if label.is_empty() {
+            return false;
+        }
+        for &b in label.as_bytes() {
+            if !is_valid_domain_byte_fast(b) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Decode a display name, which is either a run of atoms or a quoted-string.
+fn decode_display_name(s: &str) -> Option<String> {
+    if s.is_empty() {
+        return None;
+    }
+    if s.as_bytes()[0] == b'"' {
+        let bytes = s.as_bytes();
+        let end = find_quote_end(bytes)?;
+        let inner = &s[1..end];
+        Some(unescape(inner))
+    } else {
+        Some(s.to_string())
+    }
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(next) = chars.next() {
+                out.push(next);
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Find the `:` that separates a group display-name from its member list,
+/// ignoring any `:` inside a quoted-string or a domain literal.
+fn find_group_colon(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let mut in_quotes = false;
+    let mut in_brackets = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => in_quotes = !in_quotes,
+            b'\\' if in_quotes => i += 1,
+            b'<' => return None, // a plain mailbox never has a colon before '<'
+            b'[' if !in_quotes => in_brackets = true,
+            b']' if !in_quotes => in_brackets = false,
+            b':' if !in_quotes && !in_brackets => return Some(i),
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Split a group's member list on top-level commas.
+fn split_addresses(s: &str) -> Vec<&str> {
+    split_top_level_entries(s)
+}
+
+/// Split on commas that are not inside a quoted-string or `<...>`/`[...]`.
+fn split_top_level_entries(s: &str) -> Vec<&str> {
+    let bytes = s.as_bytes();
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut angle_depth = 0i32;
+    let mut bracket_depth = 0i32;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => in_quotes = !in_quotes,
+            b'\\' if in_quotes => i += 1,
+            b'<' if !in_quotes => angle_depth += 1,
+            b'>' if !in_quotes => angle_depth -= 1,
+            b'[' if !in_quotes => bracket_depth += 1,
+            b']' if !in_quotes => bracket_depth -= 1,
+            b',' if !in_quotes && angle_depth == 0 && bracket_depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    if start < s.len() {
+        parts.push(&s[start..]);
+    }
+    parts
+}
+
+/// Unfold CRLF+WSP folding whitespace into nothing extra (headers arrive
+/// as a single logical line once folding is undone).
+fn unfold(s: &str) -> String {
+    s.replace("\r\n", "").replace(['\r', '\n'], "")
+}
+
+/// Strip `(...)` comments, honouring nesting to any depth and
+/// backslash-escaped characters inside the comment.
+fn strip_comments(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = String::with_capacity(s.len());
+    let mut depth = 0u32;
+    let mut in_quotes = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if in_quotes {
+            out.push(b as char);
+            if b == b'\\' && i + 1 < bytes.len() {
+                i += 1;
+                out.push(bytes[i] as char);
+            } else if b == b'"' {
+                in_quotes = false;
+            }
+            i += 1;
+            continue;
+        }
+        match b {
+            b'"' => {
+                in_quotes = true;
+                out.push('"');
+            }
+            b'(' => depth += 1,
+            b')' if depth > 0 => depth -= 1,
+            b'\\' if depth > 0 && i + 1 < bytes.len() => i += 1,
+            _ if depth == 0 => out.push(b as char),
+            _ => {}
+        }
+        i += 1;
+    }
+    out
+}
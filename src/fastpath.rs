@@ -2,6 +2,8 @@
 
 use std::sync::OnceLock;
 
+use crate::intl;
+
 /// Cache for common domains to avoid repeated validation
 static COMMON_DOMAINS: OnceLock<std::collections::HashSet<&'static str>> = OnceLock::new();
 
@@ -52,7 +54,25 @@ pub fn fast_ascii_email_check(email: &str) -> Option<bool> {
     if domain.len() < 3 || domain.len() > 253 {
         return Some(false);
     }
-    
+
+    // A quoted local part (`"john doe"@example.com`) needs the full
+    // qcontent parser.
+    if local[0] == b'"' {
+        return None;
+    }
+
+    // A local part with a high byte is UTF-8, not ASCII atext - hand the
+    // whole address to the internationalized validator rather than
+    // scanning it byte-by-byte against the ASCII-only tables below.
+    if intl::needs_intl_path(local) {
+        return Some(intl::validate_intl(email));
+    }
+
+    // Address literals (`[192.168.0.1]`) need the full bracket parser.
+    if domain[0] == b'[' {
+        return None;
+    }
+
     // Must have dot in domain
     let dot_pos = domain.iter().position(|&b| b == b'.')?;
     if dot_pos == 0 || dot_pos == domain.len() - 1 {
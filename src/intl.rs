@@ -0,0 +1,115 @@
+//! Internationalized (EAI) email validation - Unicode local parts and
+//! IDNA domains, reached from the fast ASCII scanner only once it sees a
+//! high byte via `lookup::requires_utf8_check`.
+
+use crate::lookup::{is_valid_domain_byte_fast, requires_utf8_check};
+use crate::punycode;
+
+const MAX_LABEL_OCTETS: usize = 63;
+const MAX_DOMAIN_OCTETS: usize = 255;
+
+/// Validate an internationalized address: a UTF-8 local part (any
+/// assigned scalar value beyond ASCII atext) and a domain whose labels
+/// are either ASCII, an A-label (`xn--` punycode that decodes cleanly),
+/// or a U-label (non-ASCII that punycode-encodes to <= 63 bytes).
+pub fn validate_intl(email: &str) -> bool {
+    let Some(at_pos) = email.find('@') else {
+        return false;
+    };
+
+    let local = &email[..at_pos];
+    let domain = &email[at_pos + 1..];
+
+    if local.is_empty() || local.len() > 64 {
+        return false;
+    }
+    if domain.is_empty() || domain.len() > MAX_DOMAIN_OCTETS {
+        return false;
+    }
+
+    validate_intl_local(local) && validate_intl_domain(domain)
+}
+
+fn validate_intl_local(local: &str) -> bool {
+    let bytes = local.as_bytes();
+    if bytes[0] == b'.' || bytes[bytes.len() - 1] == b'.' {
+        return false;
+    }
+
+    let mut prev_dot = false;
+    for c in local.chars() {
+        if c == '.' {
+            if prev_dot {
+                return false;
+            }
+            prev_dot = true;
+            continue;
+        }
+        prev_dot = false;
+
+        if (c as u32) < 128 {
+            if !crate::lookup::is_valid_local_byte_fast(c as u8) {
+                return false;
+            }
+        } else if !is_assigned_scalar(c) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Approximate check for "assigned Unicode scalar value beyond ASCII
+/// atext": rejects control characters, the zero-width characters
+/// U+200B-U+200D and the BOM (U+FEFF). Unassigned code points and other
+/// formatting characters are not detected.
+fn is_assigned_scalar(c: char) -> bool {
+    !c.is_control() && !matches!(c, '\u{200B}'..='\u{200D}' | '\u{FEFF}')
+}
+
+fn validate_intl_domain(domain: &str) -> bool {
+    if !domain.contains('.') {
+        return false;
+    }
+
+    for label in domain.split('.') {
+        if !validate_label(label) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn validate_label(label: &str) -> bool {
+    // A U-label's limit applies to its encoded form, checked below.
+    if label.is_empty() || (label.is_ascii() && label.len() > MAX_LABEL_OCTETS) {
+        return false;
+    }
+
+    if let Some(rest) = label.strip_prefix("xn--") {
+        // A-label: must decode to a non-empty Unicode string.
+        return punycode::decode(rest).is_some_and(|decoded| !decoded.is_empty());
+    }
+
+    if label.is_ascii() {
+        let bytes = label.as_bytes();
+        if bytes[0] == b'-' || bytes[bytes.len() - 1] == b'-' {
+            return false;
+        }
+        return bytes.iter().all(|&b| is_valid_domain_byte_fast(b));
+    }
+
+    // U-label: must punycode-encode to a short enough A-label.
+    match punycode::encode(label) {
+        Some(encoded) => encoded.len() + "xn--".len() <= MAX_LABEL_OCTETS,
+        None => false,
+    }
+}
+
+/// Cheap pre-check used by the fast ASCII scanner: does this local part
+/// contain a byte that requires falling back to [`validate_intl`]?
+#[inline(always)]
+pub fn needs_intl_path(local: &[u8]) -> bool {
+    local.iter().any(|&b| requires_utf8_check(b))
+}
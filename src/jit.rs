@@ -2,12 +2,15 @@
 
 /// Validation state machine for streaming validation
 #[derive(Clone, Copy)]
-#[allow(dead_code)]
 pub struct ValidationState {
     state: u8,
     at_count: u8,
     dot_count: u8,
     last_char: u8,
+    local_len: u16,
+    domain_len: u16,
+    total_len: u16,
+    at_pos: Option<u16>,
 }
 
 impl ValidationState {
@@ -17,58 +20,91 @@ impl ValidationState {
             at_count: 0,
             dot_count: 0,
             last_char: 0,
+            local_len: 0,
+            domain_len: 0,
+            total_len: 0,
+            at_pos: None,
         }
     }
-    
+
     #[inline(always)]
     pub fn transition(&mut self, b: u8) {
+        self.total_len += 1;
+        if self.total_len > 254 {
+            self.state = 255;
+            return;
+        }
+
         match self.state {
             0 => { // local_start
                 if b == b'@' || b == b'.' {
                     self.state = 255; // reject
                 } else {
+                    self.local_len = 1;
                     self.state = 1;
                 }
             }
             1 => { // local
                 if b == b'@' {
-                    self.at_count += 1;
-                    if self.at_count > 1 {
+                    if self.last_char == b'.' {
                         self.state = 255;
                     } else {
-                        self.state = 2;
+                        self.at_count += 1;
+                        if self.at_count > 1 {
+                            self.state = 255;
+                        } else {
+                            self.at_pos = Some(self.total_len - 1);
+                            self.state = 2;
+                        }
                     }
                 } else if b == b'.' && self.last_char == b'.' {
                     self.state = 255;
+                } else {
+                    self.local_len += 1;
+                    if self.local_len > 64 {
+                        self.state = 255;
+                    }
                 }
             }
             2 => { // domain_start
                 if b == b'.' || b == b'@' {
                     self.state = 255;
                 } else {
+                    self.domain_len = 1;
                     self.state = 3;
                 }
             }
             3 => { // domain
                 if b == b'@' {
                     self.state = 255;
-                } else if b == b'.' {
-                    self.dot_count += 1;
+                } else if b == b'.' && self.last_char == b'.' {
+                    self.state = 255;
+                } else {
+                    if b == b'.' {
+                        self.dot_count += 1;
+                    }
+                    self.domain_len += 1;
+                    if self.domain_len > 253 {
+                        self.state = 255;
+                    }
                 }
             }
             _ => {}
         }
         self.last_char = b;
     }
-    
+
     #[inline(always)]
     pub fn is_rejected(&self) -> bool {
         self.state == 255
     }
-    
+
     #[inline(always)]
     pub fn can_accept(&self) -> bool {
-        self.state == 3 && self.at_count == 1 && self.dot_count >= 1
+        self.state == 3
+            && self.at_count == 1
+            && self.dot_count >= 1
+            && self.last_char != b'.'
     }
 }
 
@@ -80,9 +116,66 @@ pub fn find_at_fast(s: &str) -> Option<usize> {
 }
 
 /// Streaming validation result
-#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StreamingResult {
     Valid,
     Invalid,
     NeedMore,
 }
+
+/// Incremental email validator: feed it bytes as they arrive off a socket
+/// or buffer and it rejects malformed input before the whole address has
+/// been read, instead of buffering everything up front.
+pub struct StreamValidator {
+    state: ValidationState,
+    buffer: Vec<u8>,
+}
+
+impl StreamValidator {
+    pub fn new() -> Self {
+        Self {
+            state: ValidationState::new(),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Feed the next chunk of bytes. Once a chunk makes the state machine
+    /// reject, every subsequent `push` keeps returning `Invalid` without
+    /// re-scanning already-consumed bytes.
+    pub fn push(&mut self, chunk: &[u8]) -> StreamingResult {
+        if self.state.is_rejected() {
+            return StreamingResult::Invalid;
+        }
+
+        for &b in chunk {
+            self.state.transition(b);
+            if self.state.is_rejected() {
+                self.buffer.clear();
+                return StreamingResult::Invalid;
+            }
+            self.buffer.push(b);
+        }
+
+        if self.state.can_accept() {
+            StreamingResult::Valid
+        } else {
+            StreamingResult::NeedMore
+        }
+    }
+
+    /// Split the accepted input into its local part and domain. Returns
+    /// `None` if the address hasn't reached an accepting state yet.
+    pub fn finish(&self) -> Option<(&[u8], &[u8])> {
+        if !self.state.can_accept() {
+            return None;
+        }
+        let at_pos = self.state.at_pos? as usize;
+        Some((&self.buffer[..at_pos], &self.buffer[at_pos + 1..]))
+    }
+}
+
+impl Default for StreamValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
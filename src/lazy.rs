@@ -1,6 +1,7 @@
 //! Lazy email validation - minimal allocations
 
 use std::sync::Arc;
+use unicode_normalization::UnicodeNormalization;
 
 /// Zero-allocation email view
 /// Holds references to original string instead of copying
@@ -34,19 +35,24 @@ impl LazyEmailView {
         &self.original[self.at_pos + 1..]
     }
     
-    /// Get normalized form (computed on demand)
+    /// Get normalized form (computed on demand), or `None` if the domain
+    /// doesn't convert cleanly to ASCII - see `ascii_domain()`.
     #[inline]
-    pub fn normalized(&self) -> String {
-        format!("{}@{}", 
+    pub fn normalized(&self) -> Option<String> {
+        Some(format!("{}@{}",
             self.local_part().to_lowercase(),
-            self.domain().to_lowercase()
-        )
+            self.ascii_domain()?
+        ))
     }
-    
-    /// Get ASCII domain (computed on demand)
+
+    /// Get ASCII domain (computed on demand): UTS-46 ToASCII per label -
+    /// lowercase/NFC-normalize, then Punycode-encode (`xn--`) any label
+    /// that isn't already pure ASCII. Returns `None` if any label fails
+    /// to convert (oversized, or an encoder error), rather than handing
+    /// back a non-ASCII domain a caller might mistake for SMTP-ready.
     #[inline]
-    pub fn ascii_domain(&self) -> String {
-        self.domain().to_lowercase()
+    pub fn ascii_domain(&self) -> Option<String> {
+        to_ascii_domain(self.domain())
     }
     
     /// Get original
@@ -62,6 +68,32 @@ impl LazyEmailView {
     }
 }
 
+/// UTS-46 ToASCII for one domain label: casefold + NFC-normalize, then
+/// Punycode-encode (`xn--` prefixed) unless the result is already ASCII.
+/// Returns `None` if the encoded label would exceed 63 octets.
+fn label_to_ascii(label: &str) -> Option<String> {
+    let folded: String = label.to_lowercase().nfc().collect();
+
+    if folded.is_ascii() {
+        return (folded.len() <= 63).then_some(folded);
+    }
+
+    let encoded = crate::punycode::encode(&folded)?;
+    let out = format!("xn--{encoded}");
+    (out.len() <= 63).then_some(out)
+}
+
+/// UTS-46 ToASCII for a full domain, label by label. Returns `None` if
+/// any label fails to convert (oversized, or an encoder error) rather
+/// than silently handing back a domain that isn't actually ASCII.
+fn to_ascii_domain(domain: &str) -> Option<String> {
+    domain
+        .split('.')
+        .map(label_to_ascii)
+        .collect::<Option<Vec<_>>>()
+        .map(|labels| labels.join("."))
+}
+
 /// String pool for reducing allocations
 pub struct StringPool {
     buffer: Vec<u8>,
@@ -97,7 +129,34 @@ impl ZeroCopyValidator {
         if len < 3 || len > 254 {
             return false;
         }
-        
+
+        // A quoted-string local part (`"john doe"@example.com`) isn't a
+        // dot-atom, so this state machine can't validate it - bail to the
+        // full address parser rather than rejecting it outright.
+        if bytes[0] == b'"' {
+            return crate::address::AddressParser::parse(email).is_some();
+        }
+
+        // Address literals (`[192.168.0.1]`, `[IPv6:...]`) don't fit the
+        // dot-atom domain state machine below, so fall through to the
+        // dedicated bracket parser once the local part checks out.
+        if let Some(at_pos) = bytes.iter().position(|&b| b == b'@') {
+            if bytes.get(at_pos + 1) == Some(&b'[') {
+                return Self::is_plain_local_part(&email[..at_pos])
+                    && crate::literal::is_valid_domain_literal(&email[at_pos + 1..]);
+            }
+        }
+
+        // A local part with a high byte is UTF-8, not ASCII atext - the
+        // dot-atom state machine below only understands ASCII, so hand
+        // the whole address to the internationalized validator instead
+        // of rejecting it outright.
+        if let Some(at_pos) = bytes.iter().position(|&b| b == b'@') {
+            if crate::intl::needs_intl_path(&bytes[..at_pos]) {
+                return crate::intl::validate_intl(email);
+            }
+        }
+
         // Single pass validation
         let mut state = ParseState::LocalStart;
         let mut at_count = 0;
@@ -157,6 +216,33 @@ impl ZeroCopyValidator {
         matches!(state, ParseState::Domain) && at_count == 1 && dot_count >= 1
     }
     
+    /// Validate a bare dot-atom local part (leading/trailing dot and
+    /// consecutive dots rejected), used by the address-literal branch.
+    fn is_plain_local_part(local: &str) -> bool {
+        if local.is_empty() || local.len() > 64 {
+            return false;
+        }
+        let bytes = local.as_bytes();
+        if bytes[0] == b'.' || bytes[bytes.len() - 1] == b'.' {
+            return false;
+        }
+        let mut prev_dot = false;
+        for &b in bytes {
+            if b == b'.' {
+                if prev_dot {
+                    return false;
+                }
+                prev_dot = true;
+            } else {
+                prev_dot = false;
+                if !Self::is_local_char(b) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
     #[inline(always)]
     const fn is_local_char(b: u8) -> bool {
         matches!(b,
@@ -9,14 +9,19 @@ mod fastpath;
 mod lazy;
 mod simd;
 
-#[allow(dead_code)]
 mod prefetch;
-#[allow(dead_code)]
 mod jit;
 #[allow(dead_code)]
 mod vectorized;
 #[allow(dead_code)]
 mod approximate;
+mod normalize;
+#[allow(dead_code)]
+mod address;
+mod punycode;
+mod intl;
+mod policy;
+mod literal;
 
 use validator::{EmailValidator as RustEmailValidator, ValidatedEmail as RustValidatedEmail};
 use simd::PortableSimd;
@@ -38,6 +43,20 @@ struct ValidatedEmail {
     ascii_domain: String,
     #[pyo3(get)]
     smtputf8: bool,
+    #[pyo3(get)]
+    canonical: String,
+    #[pyo3(get)]
+    is_quoted_local: bool,
+    #[pyo3(get)]
+    is_domain_literal: bool,
+    #[pyo3(get)]
+    is_disposable: bool,
+    #[pyo3(get)]
+    is_role_account: bool,
+    #[pyo3(get)]
+    mx_records: Vec<String>,
+    #[pyo3(get)]
+    accepts_mail: bool,
 }
 
 impl From<RustValidatedEmail> for ValidatedEmail {
@@ -49,6 +68,13 @@ impl From<RustValidatedEmail> for ValidatedEmail {
             normalized: v.normalized,
             ascii_domain: v.ascii_domain,
             smtputf8: v.smtputf8,
+            canonical: v.canonical,
+            is_quoted_local: v.is_quoted_local,
+            is_domain_literal: v.is_domain_literal,
+            is_disposable: v.is_disposable,
+            is_role_account: v.is_role_account,
+            mx_records: v.mx_records,
+            accepts_mail: v.accepts_mail,
         }
     }
 }
@@ -58,10 +84,16 @@ impl ValidatedEmail {
     fn __repr__(&self) -> String {
         format!("ValidatedEmail('{}')", self.normalized)
     }
-    
+
     fn __str__(&self) -> String {
         self.normalized.clone()
     }
+
+    /// The deduplicated "same inbox" form - e.g. `J.Doe+news@gmail.com`
+    /// and `jdoe@gmail.com` both canonicalize to `jdoe@gmail.com`.
+    fn canonicalize(&self) -> String {
+        self.canonical.clone()
+    }
 }
 
 /// Email validator with configurable options
@@ -78,13 +110,17 @@ impl EmailValidator {
         allow_smtputf8 = true,
         allow_quoted_local = false,
         allow_domain_literal = false,
-        check_deliverability = false
+        check_deliverability = false,
+        check_disposable = false,
+        check_role_account = false
     ))]
     fn new(
         allow_smtputf8: bool,
         allow_quoted_local: bool,
         allow_domain_literal: bool,
         check_deliverability: bool,
+        check_disposable: bool,
+        check_role_account: bool,
     ) -> Self {
         Self {
             inner: RustEmailValidator {
@@ -92,6 +128,8 @@ impl EmailValidator {
                 allow_quoted_local,
                 allow_domain_literal,
                 check_deliverability,
+                check_disposable,
+                check_role_account,
             },
         }
     }
@@ -200,7 +238,13 @@ pub fn is_valid_detailed(email: &str, allow_smtputf8: bool) -> bool {
     if domain.is_empty() || domain.len() > 253 {
         return false;
     }
-    
+
+    // RFC 5321 address literal (`[192.168.0.1]`, `[IPv6:...]`) - doesn't
+    // need a dot and has its own character set, so check it separately.
+    if domain.starts_with('[') {
+        return literal::is_valid_domain_literal(domain);
+    }
+
     if !domain.contains('.') {
         return false;
     }
@@ -265,15 +309,198 @@ fn batch_is_valid(emails: Vec<String>, allow_smtputf8: bool) -> Vec<bool> {
     }
 }
 
+/// Incremental email validator for bytes read off a socket or stream -
+/// rejects malformed input as soon as it's seen, instead of waiting for
+/// the whole address to arrive.
+#[pyclass]
+struct StreamValidator {
+    inner: jit::StreamValidator,
+}
+
+#[pymethods]
+impl StreamValidator {
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: jit::StreamValidator::new(),
+        }
+    }
+
+    /// Feed the next chunk of bytes. Returns `"valid"`, `"invalid"`, or
+    /// `"need_more"`.
+    fn push(&mut self, chunk: &[u8]) -> &'static str {
+        match self.inner.push(chunk) {
+            jit::StreamingResult::Valid => "valid",
+            jit::StreamingResult::Invalid => "invalid",
+            jit::StreamingResult::NeedMore => "need_more",
+        }
+    }
+
+    /// Split the accepted input into `(local_part, domain)`, or `None` if
+    /// the address hasn't reached an accepting state yet.
+    fn finish(&self) -> Option<(Vec<u8>, Vec<u8>)> {
+        self.inner
+            .finish()
+            .map(|(local, domain)| (local.to_vec(), domain.to_vec()))
+    }
+}
+
+/// Collapses syntactically distinct addresses that route to the same
+/// mailbox - subaddress tags stripped, domain folded, and optionally dots
+/// dropped for providers (like Gmail) that treat them as insignificant.
+#[pyclass]
+struct EmailNormalizer {
+    inner: normalize::EmailNormalizer,
+}
+
+#[pymethods]
+impl EmailNormalizer {
+    #[new]
+    #[pyo3(signature = (separator = "+"))]
+    fn new(separator: &str) -> PyResult<Self> {
+        let separator = separator.as_bytes().first().copied().ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err("separator must not be empty")
+        })?;
+        Ok(Self {
+            inner: normalize::EmailNormalizer::new(separator),
+        })
+    }
+
+    /// Register a domain whose local part dots should be stripped.
+    fn register_dot_insensitive_domain(&mut self, domain: &str) {
+        self.inner.register_dot_insensitive_domain(domain);
+    }
+
+    /// Register a domain that accepts mail for any local part.
+    fn register_catch_all(&mut self, domain: &str) {
+        self.inner.register_catch_all(domain);
+    }
+
+    /// Canonicalize `email`, returning `(key, original)`, or `None` if no
+    /// `@` is present.
+    fn canonicalize(&self, email: &str) -> Option<(String, String)> {
+        self.inner
+            .canonicalize(email)
+            .map(|c| (c.key, c.original))
+    }
+
+    /// True if `email` belongs to a registered catch-all `domain`.
+    fn matches_catch_all(&self, email: &str, domain: &str) -> bool {
+        self.inner.matches_catch_all(email, domain)
+    }
+}
+
+/// Validator-side policy layer: disposable-domain detection, explicit
+/// allow/block lists, and role-account classification.
+#[pyclass]
+struct DomainPolicy {
+    inner: policy::DomainPolicy,
+}
+
+#[pymethods]
+impl DomainPolicy {
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: policy::DomainPolicy::new(),
+        }
+    }
+
+    /// Load a disposable-domain list from an in-memory list.
+    fn load_disposable_domains(&mut self, domains: Vec<String>) {
+        let refs: Vec<&str> = domains.iter().map(String::as_str).collect();
+        self.inner.load_disposable_domains(&refs);
+    }
+
+    /// Load a disposable-domain list from a newline-delimited file.
+    fn load_disposable_file(&mut self, path: &str) -> PyResult<()> {
+        self.inner
+            .load_disposable_file(path)
+            .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))
+    }
+
+    /// Add `domain` to the allow list.
+    fn allow_domain(&mut self, domain: &str) {
+        self.inner.allow_domain(domain);
+    }
+
+    /// Add `domain` to the block list.
+    fn block_domain(&mut self, domain: &str) {
+        self.inner.block_domain(domain);
+    }
+
+    /// Register an additional role-account local part.
+    fn register_role_account(&mut self, local_part: &str) {
+        self.inner.register_role_account(local_part);
+    }
+
+    /// True if `local_part` identifies a role account rather than a person.
+    fn is_role_account(&self, local_part: &str) -> bool {
+        self.inner.is_role_account(local_part)
+    }
+
+    /// Check `email` against syntax plus the configured policy. Returns
+    /// `"valid"`, `"invalid_syntax"`, `"blocked_domain"`,
+    /// `"disposable_domain"`, or `"not_in_allow_list"`.
+    fn check(&self, email: &str) -> &'static str {
+        match self.inner.check(email) {
+            policy::PolicyOutcome::Valid => "valid",
+            policy::PolicyOutcome::InvalidSyntax => "invalid_syntax",
+            policy::PolicyOutcome::BlockedDomain => "blocked_domain",
+            policy::PolicyOutcome::DisposableDomain => "disposable_domain",
+            policy::PolicyOutcome::NotInAllowList => "not_in_allow_list",
+        }
+    }
+}
+
+/// Validate every `\n`/`\r\n`-delimited address in a file too large to
+/// load as a `Vec<String>`, memory-mapping it instead of reading it into
+/// a buffer first.
+#[pyfunction]
+fn validate_address_file(path: &str) -> PyResult<Vec<bool>> {
+    let results = prefetch::MmapBatchValidator::validate_file(path)
+        .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
+    Ok(results.into_iter().map(|(_, valid)| valid).collect())
+}
+
+/// Resolve MX/A records for a batch of already-syntax-valid domains
+/// concurrently, instead of blocking on one DNS round-trip per call.
+/// Requires the `deliverability` feature.
+#[cfg(feature = "deliverability")]
+#[pyfunction]
+#[pyo3(signature = (emails, *, allow_smtputf8 = true))]
+fn batch_check_deliverability(py: Python<'_>, emails: Vec<String>, allow_smtputf8: bool) -> PyResult<Bound<'_, PyAny>> {
+    let validator = RustEmailValidator {
+        allow_smtputf8,
+        check_deliverability: true,
+        ..Default::default()
+    };
+    pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let results = validator::validate_batch_async(&validator, &emails).await;
+        // Unresolvable/invalid addresses surface as `None` rather than
+        // aborting the whole batch on the first failure.
+        Ok(results
+            .into_iter()
+            .map(|r| r.ok().map(ValidatedEmail::from))
+            .collect::<Vec<_>>())
+    })
+}
+
 /// pyval module
 #[pymodule]
 fn pyval(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<ValidatedEmail>()?;
     m.add_class::<EmailValidator>()?;
+    m.add_class::<StreamValidator>()?;
+    m.add_class::<EmailNormalizer>()?;
+    m.add_class::<DomainPolicy>()?;
     m.add_function(wrap_pyfunction!(validate_email, m)?)?;
     m.add_function(wrap_pyfunction!(is_valid, m)?)?;
     m.add_function(wrap_pyfunction!(is_valid_ultra, m)?)?;
     m.add_function(wrap_pyfunction!(batch_is_valid, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_address_file, m)?)?;
+    #[cfg(feature = "deliverability")]
+    m.add_function(wrap_pyfunction!(batch_check_deliverability, m)?)?;
     m.add("__version__", "0.2.0")?;
     Ok(())
 }
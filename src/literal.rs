@@ -0,0 +1,48 @@
+//! RFC 5321 §4.1.3 address-literal domain parsing, shared by the fast
+//! validation paths so they can fall through cleanly on a leading `[`.
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Validate a bracketed domain-literal: an `IPv4-address-literal`, an
+/// `IPv6:`-tagged `IPv6-address-literal`, or the `General-address-literal`
+/// fallback (`[tag:value]`).
+pub fn is_valid_domain_literal(domain: &str) -> bool {
+    if domain.len() < 3 || !domain.starts_with('[') || !domain.ends_with(']') {
+        return false;
+    }
+
+    let inner = &domain[1..domain.len() - 1];
+    if inner.is_empty() {
+        return false;
+    }
+
+    if let Some(ipv6) = inner.strip_prefix("IPv6:") {
+        return ipv6.parse::<Ipv6Addr>().is_ok();
+    }
+
+    if inner.parse::<Ipv4Addr>().is_ok() {
+        return true;
+    }
+
+    match inner.find(':') {
+        Some(colon) => is_standardized_tag(&inner[..colon]) && is_dcontent(&inner[colon + 1..]),
+        None => false,
+    }
+}
+
+/// `Standardized-tag` - a dot-separated run of Ldh (letter/digit/hyphen)
+/// segments identifying the literal's address family.
+fn is_standardized_tag(tag: &str) -> bool {
+    !tag.is_empty()
+        && tag.split('.').all(|seg| {
+            !seg.is_empty()
+                && !seg.starts_with('-')
+                && !seg.ends_with('-')
+                && seg.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        })
+}
+
+/// `dtext` - printable ASCII excluding `[`, `]`, and `\`.
+fn is_dcontent(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| matches!(b, 33..=90 | 94..=126))
+}
@@ -6,13 +6,20 @@ pub static LOCAL_PART_TABLE: [u8; 256] = {
     let mut table = [0u8; 256];
     let mut i = 0;
     while i < 256 {
-        let valid = matches!(i as u8,
+        let b = i as u8;
+        let valid = matches!(b,
             b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' |
             b'!' | b'#' | b'$' | b'%' | b'&' | b'\'' | b'*' | b'+' | b'-' | b'/' |
             b'=' | b'?' | b'^' | b'_' | b'`' | b'{' | b'|' | b'}' | b'~' | b'.'
         );
         if valid {
-            table[i] = 1;
+            table[i] |= 0b01;
+        }
+        // High bytes are the lead/continuation bytes of a UTF-8 sequence;
+        // they're not themselves valid ASCII atext, but they flag that the
+        // scalar scanner must bail out to the internationalized path.
+        if b >= 128 {
+            table[i] |= 0b10;
         }
         i += 1;
     }
@@ -38,7 +45,15 @@ pub static DOMAIN_TABLE: [u8; 256] = {
 /// Fast check if byte is valid local part char using lookup table
 #[inline(always)]
 pub fn is_valid_local_byte_fast(b: u8) -> bool {
-    LOCAL_PART_TABLE[b as usize] != 0
+    LOCAL_PART_TABLE[b as usize] & 0b01 != 0
+}
+
+/// True if this byte is a UTF-8 lead/continuation byte, meaning the fast
+/// ASCII scanner must bail out to [`crate::intl::validate_intl`] instead
+/// of rejecting the address outright.
+#[inline(always)]
+pub fn requires_utf8_check(b: u8) -> bool {
+    LOCAL_PART_TABLE[b as usize] & 0b10 != 0
 }
 
 /// Fast check if byte is valid domain char using lookup table
@@ -55,24 +70,49 @@ pub fn has_consecutive_dots(s: &str) -> bool {
     if bytes.len() < 2 {
         return false;
     }
-    
+
     // Check 8 bytes at a time using u64
     let mut i = 0;
+    // Was the last lane of the previous chunk a dot? Needed to catch a
+    // pair that straddles the boundary between two chunks.
+    let mut carry_dot = false;
     while i + 8 <= bytes.len() {
         let chunk = u64::from_le_bytes([
             bytes[i], bytes[i+1], bytes[i+2], bytes[i+3],
             bytes[i+4], bytes[i+5], bytes[i+6], bytes[i+7]
         ]);
-        // Magic: check for consecutive dots using bit manipulation
-        // Each dot is 0x2E = 0b00101110
-        let dots = chunk ^ 0x2E2E2E2E2E2E2E2Eu64;
-        // TODO: more sophisticated check needed
+
+        // Each dot is 0x2E; XOR zeroes out lanes that hold one. Adding 0x7F
+        // to each lane's low seven bits cannot carry into the next lane, so
+        // the mask has the high bit set in exactly the lanes that held a dot.
+        let x = chunk ^ 0x2E2E2E2E2E2E2E2Eu64;
+        let zero_mask = !(((x & 0x7F7F7F7F7F7F7F7Fu64).wrapping_add(0x7F7F7F7F7F7F7F7Fu64))
+            | x
+            | 0x7F7F7F7F7F7F7F7Fu64);
+
+        // Adjacent dots within this chunk: lane n and lane n+1 both set.
+        // Shifting right by 8 moves lane n+1's flag into lane n's slot
+        // (little-endian byte order), so ANDing finds the overlap.
+        if zero_mask & (zero_mask >> 8) != 0 {
+            return true;
+        }
+
+        // Adjacent dots straddling the chunk boundary: previous chunk's
+        // last lane was a dot and this chunk's first lane is too.
+        if carry_dot && zero_mask & 0x80 != 0 {
+            return true;
+        }
+
+        carry_dot = zero_mask & 0x8000_0000_0000_0000 != 0;
         i += 8;
     }
-    
-    // Fallback to byte-by-byte for remaining
-    for i in 0..bytes.len() - 1 {
-        if bytes[i] == b'.' && bytes[i + 1] == b'.' {
+
+    // Fallback to byte-by-byte for the tail (fewer than 8 bytes remain).
+    if carry_dot && i < bytes.len() && bytes[i] == b'.' {
+        return true;
+    }
+    for w in bytes[i..].windows(2) {
+        if w[0] == b'.' && w[1] == b'.' {
             return true;
         }
     }
@@ -99,16 +139,18 @@ pub fn count_at_swar(s: &str) -> (usize, Option<usize>) {
         // SWAR technique: find @ (0x40) in parallel
         let xor = chunk ^ 0x4040404040404040u64;
         let low_bits = xor.wrapping_sub(0x0101010101010101u64) & !xor & 0x8080808080808080u64;
-        
+
         if low_bits != 0 {
-            // At least one @ found in this chunk
-            for j in 0..8 {
-                if bytes[i + j] == b'@' {
-                    count += 1;
-                    if first_pos.is_none() {
-                        first_pos = Some(i + j);
-                    }
-                }
+            // The borrow in `low_bits` can also flag an 'A' (0x41) lane right
+            // after an '@'. This carry-free mask sets exactly one high bit per
+            // '@' lane, so its popcount is the match count and its lowest set
+            // bit's lane is the first match - no need to rescan byte-by-byte.
+            let matches = !(((xor & 0x7F7F7F7F7F7F7F7Fu64).wrapping_add(0x7F7F7F7F7F7F7F7Fu64))
+                | xor
+                | 0x7F7F7F7F7F7F7F7Fu64);
+            count += matches.count_ones() as usize;
+            if first_pos.is_none() {
+                first_pos = Some(i + (matches.trailing_zeros() / 8) as usize);
             }
         }
         i += 8;
@@ -0,0 +1,109 @@
+//! Mailbox-level canonicalization - collapsing syntactically distinct
+//! addresses that a real mail server would route to the same mailbox.
+
+use std::collections::HashSet;
+
+/// Canonical form of an address alongside the original input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanonicalEmail {
+    /// Deduplication key: subaddress stripped, domain folded.
+    pub key: String,
+    /// The address as originally provided.
+    pub original: String,
+}
+
+/// Canonicalizes addresses the way a mail server's recipient resolution
+/// does: strip the subaddress tag, fold the domain, and optionally drop
+/// dots in the local part for providers that treat them as insignificant.
+pub struct EmailNormalizer {
+    separator: u8,
+    dot_insensitive_domains: HashSet<String>,
+    catch_all_domains: HashSet<String>,
+}
+
+impl Default for EmailNormalizer {
+    fn default() -> Self {
+        Self::new(b'+')
+    }
+}
+
+impl EmailNormalizer {
+    /// Create a normalizer using `separator` to mark subaddress tags
+    /// (`+` for most providers, `-` for some).
+    pub fn new(separator: u8) -> Self {
+        let mut dot_insensitive_domains = HashSet::new();
+        dot_insensitive_domains.insert("gmail.com".to_string());
+
+        Self {
+            separator,
+            dot_insensitive_domains,
+            catch_all_domains: HashSet::new(),
+        }
+    }
+
+    /// Register a domain whose local part dots should be stripped
+    /// (e.g. Gmail treats `j.doe` and `jdoe` as the same mailbox).
+    pub fn register_dot_insensitive_domain(&mut self, domain: &str) {
+        self.dot_insensitive_domains.insert(domain.to_lowercase());
+    }
+
+    /// Register a domain that is known to accept mail for any local
+    /// part (a catch-all), so distinct addresses at that domain should
+    /// be treated as the same mailbox for dedup/blocklist purposes.
+    pub fn register_catch_all(&mut self, domain: &str) {
+        self.catch_all_domains.insert(domain.to_lowercase());
+    }
+
+    /// Canonicalize `email`, returning both the dedup key and the
+    /// original address. Returns `None` if no `@` is present.
+    pub fn canonicalize(&self, email: &str) -> Option<CanonicalEmail> {
+        let at_pos = email.find('@')?;
+        let local = &email[..at_pos];
+        let domain = &email[at_pos + 1..];
+
+        let tag_pos = local.bytes().position(|b| b == self.separator);
+        let base_local = match tag_pos {
+            Some(pos) => &local[..pos],
+            None => local,
+        };
+
+        let folded_domain = fold_domain(domain);
+        let lower_local = base_local.to_lowercase();
+
+        let key_local = if self.dot_insensitive_domains.contains(&folded_domain) {
+            lower_local.replace('.', "")
+        } else {
+            lower_local
+        };
+
+        Some(CanonicalEmail {
+            key: format!("{}@{}", key_local, folded_domain),
+            original: email.to_string(),
+        })
+    }
+
+    /// True when `domain` is a registered catch-all and `email` belongs
+    /// to it - meaning it collapses with every other address at that
+    /// domain regardless of local part.
+    pub fn matches_catch_all(&self, email: &str, domain: &str) -> bool {
+        let folded = domain.to_lowercase();
+        if !self.catch_all_domains.contains(&folded) {
+            return false;
+        }
+
+        match email.rfind('@') {
+            Some(at_pos) => email[at_pos + 1..].eq_ignore_ascii_case(domain),
+            None => false,
+        }
+    }
+}
+
+/// Lowercase the domain and fold the `googlemail.com` alias into `gmail.com`.
+fn fold_domain(domain: &str) -> String {
+    let lower = domain.to_lowercase();
+    if lower == "googlemail.com" {
+        "gmail.com".to_string()
+    } else {
+        lower
+    }
+}
@@ -0,0 +1,146 @@
+//! Pluggable domain policy: disposable/allow/block lists and role-account
+//! rules consulted after syntax validation passes - the analogue of a
+//! mail server's recipient directory lookup.
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+use crate::approximate::EmailFilter;
+
+/// Outcome of a policy check, richer than a bare `bool` so callers can
+/// tell *why* an address was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyOutcome {
+    Valid,
+    InvalidSyntax,
+    BlockedDomain,
+    DisposableDomain,
+    NotInAllowList,
+}
+
+/// Built-in local parts that identify a role account rather than a
+/// person (`admin@`, `postmaster@`, ...).
+fn default_role_accounts() -> HashSet<String> {
+    [
+        "admin",
+        "administrator",
+        "postmaster",
+        "webmaster",
+        "hostmaster",
+        "abuse",
+        "noreply",
+        "no-reply",
+        "support",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+/// A validator-side policy layer: disposable-domain detection, explicit
+/// allow/block lists, and role-account classification.
+pub struct DomainPolicy {
+    // Bloom filter for a (potentially large) disposable-domain set, backed
+    // by an exact set to resolve the filter's ~1% false-positive rate.
+    disposable_filter: EmailFilter,
+    disposable_exact: HashSet<String>,
+    allow_list: Option<HashSet<String>>,
+    block_list: HashSet<String>,
+    role_accounts: HashSet<String>,
+}
+
+impl Default for DomainPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DomainPolicy {
+    pub fn new() -> Self {
+        Self {
+            disposable_filter: EmailFilter::new(),
+            disposable_exact: HashSet::new(),
+            allow_list: None,
+            block_list: HashSet::new(),
+            role_accounts: default_role_accounts(),
+        }
+    }
+
+    /// Load a disposable-domain list from an in-memory slice.
+    pub fn load_disposable_domains(&mut self, domains: &[&str]) {
+        for domain in domains {
+            self.register_disposable(domain);
+        }
+    }
+
+    /// Load a disposable-domain list from a newline-delimited file.
+    pub fn load_disposable_file(&mut self, path: &str) -> io::Result<()> {
+        let file = File::open(path)?;
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            let domain = line.trim();
+            if !domain.is_empty() {
+                self.register_disposable(domain);
+            }
+        }
+        Ok(())
+    }
+
+    fn register_disposable(&mut self, domain: &str) {
+        let lower = domain.to_lowercase();
+        self.disposable_filter.add(&lower);
+        self.disposable_exact.insert(lower);
+    }
+
+    /// Add `domain` to the allow list. Once any domain is allow-listed,
+    /// domains outside the list are rejected with `NotInAllowList`.
+    pub fn allow_domain(&mut self, domain: &str) {
+        self.allow_list
+            .get_or_insert_with(HashSet::new)
+            .insert(domain.to_lowercase());
+    }
+
+    /// Add `domain` to the block list.
+    pub fn block_domain(&mut self, domain: &str) {
+        self.block_list.insert(domain.to_lowercase());
+    }
+
+    /// Register an additional role-account local part (e.g. `sales`).
+    pub fn register_role_account(&mut self, local_part: &str) {
+        self.role_accounts.insert(local_part.to_lowercase());
+    }
+
+    /// True if `local_part` identifies a role account rather than a person.
+    pub fn is_role_account(&self, local_part: &str) -> bool {
+        self.role_accounts.contains(&local_part.to_lowercase())
+    }
+
+    /// Check `email` against syntax plus the configured policy.
+    pub fn check(&self, email: &str) -> PolicyOutcome {
+        if !crate::lazy::ZeroCopyValidator::validate_no_alloc(email) {
+            return PolicyOutcome::InvalidSyntax;
+        }
+
+        // Syntax already guarantees an '@'; a quoted local part may hold
+        // more, so the last one starts the domain.
+        let at_pos = email.rfind('@').unwrap();
+        let domain = email[at_pos + 1..].to_lowercase();
+
+        if self.block_list.contains(&domain) {
+            return PolicyOutcome::BlockedDomain;
+        }
+
+        if self.disposable_filter.might_be_valid(&domain) && self.disposable_exact.contains(&domain) {
+            return PolicyOutcome::DisposableDomain;
+        }
+
+        if let Some(allow_list) = &self.allow_list {
+            if !allow_list.contains(&domain) {
+                return PolicyOutcome::NotInAllowList;
+            }
+        }
+
+        PolicyOutcome::Valid
+    }
+}
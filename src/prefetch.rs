@@ -1,5 +1,9 @@
 //! Memory prefetching and cache optimization
 
+use std::fs::File;
+use std::io;
+use std::ops::Range;
+
 /// Prefetch hints for cache optimization
 #[inline(always)]
 #[allow(dead_code)]
@@ -230,6 +234,64 @@ pub fn pipelined_validation(emails: &[&str]) -> Vec<bool> {
     
     results[len - 2] = r1;
     results[len - 1] = r2;
-    
+
     results
 }
+
+/// Memory-mapped batch validator for newline-delimited address dumps
+/// (bounce lists, suppression files) too large to load as `Vec<String>`.
+pub struct MmapBatchValidator;
+
+impl MmapBatchValidator {
+    /// Memory-map `path` read-only and validate each `\n`/`\r\n`-delimited
+    /// line directly against the mapped bytes, with no per-line allocation.
+    /// Returns each line's byte range in the file alongside its result.
+    pub fn validate_file(path: &str) -> io::Result<Vec<(Range<usize>, bool)>> {
+        let file = File::open(path)?;
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        let bytes: &[u8] = &mmap;
+        let len = bytes.len();
+
+        let mut results = Vec::new();
+        let mut start = 0usize;
+
+        while start < len {
+            let mut end = start;
+            while end < len && bytes[end] != b'\n' {
+                end += 1;
+            }
+
+            // Prefetch the next line's start while we validate this one.
+            if end + 1 < len {
+                prefetch_read(bytes[end + 1..].as_ptr());
+            }
+
+            let mut line_end = end;
+            if line_end > start && bytes[line_end - 1] == b'\r' {
+                line_end -= 1;
+            }
+
+            let valid = std::str::from_utf8(&bytes[start..line_end])
+                .map(crate::lazy::ZeroCopyValidator::validate_no_alloc)
+                .unwrap_or(false);
+            results.push((start..line_end, valid));
+
+            start = end + 1;
+        }
+
+        Ok(results)
+    }
+
+    /// Same as [`validate_file`](Self::validate_file) but packs results into
+    /// a bitset (one bit per line, in file order) instead of a `Vec`.
+    pub fn validate_file_bitset(path: &str) -> io::Result<Vec<u8>> {
+        let results = Self::validate_file(path)?;
+        let mut bits = vec![0u8; results.len().div_ceil(8)];
+        for (i, (_, valid)) in results.iter().enumerate() {
+            if *valid {
+                bits[i / 8] |= 1 << (i % 8);
+            }
+        }
+        Ok(bits)
+    }
+}
@@ -0,0 +1,164 @@
+//! Bootstring (RFC 3492) Punycode encoder/decoder used for IDNA labels.
+
+const BASE: u32 = 36;
+const TMIN: u32 = 1;
+const TMAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+const DELIMITER: char = '-';
+
+fn adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
+    delta /= if first_time { DAMP } else { 2 };
+    delta += delta / num_points;
+
+    let mut k = 0;
+    while delta > ((BASE - TMIN) * TMAX) / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+    k + (((BASE - TMIN + 1) * delta) / (delta + SKEW))
+}
+
+fn digit_to_char(d: u32) -> char {
+    if d < 26 {
+        (b'a' + d as u8) as char
+    } else {
+        (b'0' + (d - 26) as u8) as char
+    }
+}
+
+fn char_to_digit(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32),
+        'A'..='Z' => Some(c as u32 - 'A' as u32),
+        '0'..='9' => Some(c as u32 - '0' as u32 + 26),
+        _ => None,
+    }
+}
+
+/// Encode a Unicode label into the bootstring portion (without the
+/// `xn--` ACE prefix).
+pub fn encode(input: &str) -> Option<String> {
+    let code_points: Vec<u32> = input.chars().map(|c| c as u32).collect();
+
+    let mut output = String::new();
+    let basic: Vec<u32> = code_points.iter().copied().filter(|&c| c < 0x80).collect();
+    for &c in &basic {
+        output.push(c as u8 as char);
+    }
+
+    let mut h = basic.len() as u32;
+    let b = h;
+    if b > 0 {
+        output.push(DELIMITER);
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let total = code_points.len() as u32;
+
+    while h < total {
+        let m = code_points.iter().copied().filter(|&c| c >= n).min()?;
+
+        delta = delta.checked_add((m - n).checked_mul(h + 1)?)?;
+        n = m;
+
+        for &c in &code_points {
+            if c < n {
+                delta += 1;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = if k <= bias {
+                        TMIN
+                    } else if k >= bias + TMAX {
+                        TMAX
+                    } else {
+                        k - bias
+                    };
+
+                    if q < t {
+                        break;
+                    }
+
+                    output.push(digit_to_char(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(digit_to_char(q));
+                bias = adapt(delta, h + 1, h == b);
+                delta = 0;
+                h += 1;
+            }
+        }
+
+        delta += 1;
+        n += 1;
+    }
+
+    Some(output)
+}
+
+/// Decode the bootstring portion of a punycode label (without the
+/// `xn--` ACE prefix) back into a Unicode string.
+pub fn decode(input: &str) -> Option<String> {
+    let last_delim = input.rfind(DELIMITER);
+    let (basic, rest) = match last_delim {
+        Some(pos) => (&input[..pos], &input[pos + 1..]),
+        None => ("", input),
+    };
+
+    let mut output: Vec<u32> = basic.chars().map(|c| c as u32).collect();
+    if basic.chars().any(|c| c as u32 >= 0x80) {
+        return None;
+    }
+
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+
+    let mut chars = rest.chars().peekable();
+    while chars.peek().is_some() {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+        loop {
+            let c = chars.next()?;
+            let digit = char_to_digit(c)?;
+            i = i.checked_add(digit.checked_mul(w)?)?;
+
+            let t = if k <= bias {
+                TMIN
+            } else if k >= bias + TMAX {
+                TMAX
+            } else {
+                k - bias
+            };
+
+            if digit < t {
+                break;
+            }
+
+            w = w.checked_mul(BASE - t)?;
+            k += BASE;
+        }
+
+        let num_points = output.len() as u32 + 1;
+        bias = adapt(i - old_i, num_points, old_i == 0);
+        n = n.checked_add(i / num_points)?;
+        i %= num_points;
+
+        output.insert(i as usize, n);
+        i += 1;
+    }
+
+    output
+        .into_iter()
+        .map(char::from_u32)
+        .collect::<Option<String>>()
+}
@@ -29,11 +29,19 @@ fn validate_single_fast(email: &str) -> bool {
     
     let local = &bytes[..at_pos];
     let domain = &bytes[at_pos + 1..];
-    
+
     // Fast local validation
     if local.is_empty() || local.len() > 64 {
         return false;
     }
+
+    // A local part with a high byte is UTF-8, not ASCII atext - hand the
+    // whole address to the internationalized validator instead of
+    // running it through the ASCII-only checks below.
+    if crate::intl::needs_intl_path(local) {
+        return crate::intl::validate_intl(email);
+    }
+
     if local[0] == b'.' || local[local.len() - 1] == b'.' {
         return false;
     }
@@ -49,15 +57,23 @@ fn validate_single_fast(email: &str) -> bool {
     if domain.len() < 3 || domain.len() > 253 {
         return false;
     }
+
+    // Address literals (`[192.168.0.1]`) need the full bracket parser.
+    if domain[0] == b'[' {
+        return std::str::from_utf8(domain)
+            .map(crate::literal::is_valid_domain_literal)
+            .unwrap_or(false);
+    }
+
     if domain[0] == b'.' || domain[domain.len() - 1] == b'.' {
         return false;
     }
-    
+
     // Must have dot in domain
     if !domain.contains(&b'.') {
         return false;
     }
-    
+
     true
 }
 